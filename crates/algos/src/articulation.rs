@@ -0,0 +1,190 @@
+use crate::prelude::*;
+
+pub struct ArticulationPoints<NI> {
+    pub points: Vec<NI>,
+    pub bridges: Vec<(NI, NI)>,
+}
+
+pub fn articulation_points<NI: Idx>(graph: &DirectedCsrGraph<NI>) -> ArticulationPoints<NI> {
+    let node_count = graph.node_count().index();
+
+    let mut disc = vec![None; node_count];
+    let mut low = vec![0_usize; node_count];
+    let mut is_articulation = vec![false; node_count];
+    let mut bridges = Vec::new();
+    let mut timer = 0_usize;
+
+    struct Frame<NI> {
+        node: NI,
+        parent: Option<NI>,
+        // Only the single tree edge back to `parent` is skipped; a second
+        // occurrence (a parallel edge, or both directions of the same
+        // undirected edge appearing in `out_neighbors`/`in_neighbors`) is a
+        // real back edge and must still update `low`.
+        parent_skipped: bool,
+        neighbors: Vec<NI>,
+        next: usize,
+        children: usize,
+    }
+
+    for start in 0..node_count {
+        let start = NI::new(start);
+        if disc[start.index()].is_some() {
+            continue;
+        }
+
+        disc[start.index()] = Some(timer);
+        low[start.index()] = timer;
+        timer += 1;
+
+        let mut stack = vec![Frame {
+            node: start,
+            parent: None,
+            parent_skipped: false,
+            neighbors: undirected_neighbors(graph, start),
+            next: 0,
+            children: 0,
+        }];
+
+        while let Some(frame) = stack.last_mut() {
+            let u = frame.node;
+
+            if let Some(&v) = frame.neighbors.get(frame.next) {
+                frame.next += 1;
+
+                if Some(v) == frame.parent && !frame.parent_skipped {
+                    frame.parent_skipped = true;
+                    continue;
+                }
+
+                if let Some(v_disc) = disc[v.index()] {
+                    low[u.index()] = low[u.index()].min(v_disc);
+                } else {
+                    disc[v.index()] = Some(timer);
+                    low[v.index()] = timer;
+                    timer += 1;
+                    frame.children += 1;
+
+                    stack.push(Frame {
+                        node: v,
+                        parent: Some(u),
+                        parent_skipped: false,
+                        neighbors: undirected_neighbors(graph, v),
+                        next: 0,
+                        children: 0,
+                    });
+                }
+            } else {
+                let u_low = low[u.index()];
+                let parent = frame.parent;
+                let children = frame.children;
+                stack.pop();
+
+                match parent {
+                    Some(p) => {
+                        let p_disc = disc[p.index()].expect("parent was already discovered");
+                        low[p.index()] = low[p.index()].min(u_low);
+
+                        if u_low > p_disc {
+                            bridges.push((p, u));
+                        }
+
+                        let p_is_root = stack.len() == 1;
+                        if !p_is_root && u_low >= p_disc {
+                            is_articulation[p.index()] = true;
+                        }
+                    }
+                    None => {
+                        if children > 1 {
+                            is_articulation[u.index()] = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let points = (0..node_count)
+        .filter(|&n| is_articulation[n])
+        .map(NI::new)
+        .collect();
+
+    ArticulationPoints { points, bridges }
+}
+
+// `out_neighbors` and `in_neighbors` combined, so the traversal treats the
+// (directed) CSR as an undirected graph.
+fn undirected_neighbors<NI: Idx>(graph: &DirectedCsrGraph<NI>, u: NI) -> Vec<NI> {
+    graph
+        .out_neighbors(u)
+        .iter()
+        .chain(graph.in_neighbors(u).iter())
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bridge_between_two_triangles() {
+        // Two triangles (0,1,2) and (3,4,5) joined by the single edge (2, 3).
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+            .build();
+
+        let result = articulation_points(&graph);
+
+        assert!(result.points.contains(&2));
+        assert!(result.points.contains(&3));
+        assert_eq!(result.points.len(), 2);
+
+        assert_eq!(result.bridges.len(), 1);
+        let (u, v) = result.bridges[0];
+        assert_eq!((u.min(v), u.max(v)), (2, 3));
+    }
+
+    #[test]
+    fn duplicate_directed_edge_is_not_a_bridge() {
+        // (2, 3) is encoded as edges in both directions, the way an
+        // undirected edge naturally ends up in a directed CSR. Only the
+        // first occurrence of the parent in a child's neighbor list is the
+        // DFS tree edge; the second is a genuine extra connection, so (2, 3)
+        // must not be reported as a bridge, even though 2 and 3 remain cut
+        // vertices.
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![
+                (0, 1),
+                (1, 2),
+                (2, 0),
+                (2, 3),
+                (3, 2),
+                (3, 4),
+                (4, 5),
+                (5, 3),
+            ])
+            .build();
+
+        let result = articulation_points(&graph);
+
+        assert!(result.points.contains(&2));
+        assert!(result.points.contains(&3));
+        assert!(!result
+            .bridges
+            .iter()
+            .any(|&(u, v)| (u, v) == (2, 3) || (u, v) == (3, 2)));
+    }
+
+    #[test]
+    fn single_cycle_has_no_articulation_points() {
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 0)])
+            .build();
+
+        let result = articulation_points(&graph);
+
+        assert!(result.points.is_empty());
+        assert!(result.bridges.is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+use std::{collections::VecDeque, sync::atomic::Ordering, sync::Mutex, time::Instant};
+
+use log::info;
+
+use crate::prelude::*;
+
+// Number of source nodes to be processed in batch by a single thread. Each
+// source runs a full BFS, so this is much smaller than wcc's per-node
+// CHUNK_SIZE.
+const SOURCE_CHUNK_SIZE: usize = 64;
+
+// Below this many nodes, betweenness_centrality runs on a single thread
+// instead of paying for rayon scheduling overhead.
+const PARALLEL_THRESHOLD: usize = 1_000;
+
+pub fn betweenness_centrality<NI: Idx>(graph: &DirectedCsrGraph<NI>, undirected: bool) -> Vec<f64> {
+    let node_count = graph.node_count().index();
+
+    let mut cb = if node_count < PARALLEL_THRESHOLD {
+        betweenness_centrality_single_thread(graph, undirected)
+    } else {
+        let timer = Instant::now();
+
+        let cb = Mutex::new(vec![0.0_f64; node_count]);
+        let next_chunk = NI::zero().atomic();
+
+        rayon::scope(|s| {
+            for _ in 0..rayon::current_num_threads() {
+                s.spawn(|_| {
+                    let mut local_cb = vec![0.0_f64; node_count];
+
+                    loop {
+                        let start =
+                            next_chunk.fetch_add(NI::new(SOURCE_CHUNK_SIZE), Ordering::AcqRel);
+                        if start >= graph.node_count() {
+                            break;
+                        }
+
+                        let end = (start + NI::new(SOURCE_CHUNK_SIZE)).min(graph.node_count());
+
+                        for source in start..end {
+                            brandes_from_source(graph, source, undirected, &mut local_cb);
+                        }
+                    }
+
+                    let mut cb = cb.lock().unwrap();
+                    for (total, local) in cb.iter_mut().zip(local_cb) {
+                        *total += local;
+                    }
+                });
+            }
+        });
+
+        info!(
+            "Betweenness centrality took {} ms.",
+            timer.elapsed().as_millis()
+        );
+
+        cb.into_inner().unwrap()
+    };
+
+    // Each source/target pair is visited from both ends when the graph is
+    // scored as undirected, so every dependency is double-counted.
+    if undirected {
+        for c in &mut cb {
+            *c /= 2.0;
+        }
+    }
+
+    cb
+}
+
+fn betweenness_centrality_single_thread<NI: Idx>(
+    graph: &DirectedCsrGraph<NI>,
+    undirected: bool,
+) -> Vec<f64> {
+    let node_count = graph.node_count().index();
+    let mut cb = vec![0.0_f64; node_count];
+
+    for source in 0..graph.node_count().index() {
+        brandes_from_source(graph, NI::new(source), undirected, &mut cb);
+    }
+
+    cb
+}
+
+// Single-source pass of Brandes' algorithm, accumulating dependencies into `cb`.
+fn brandes_from_source<NI: Idx>(
+    graph: &DirectedCsrGraph<NI>,
+    s: NI,
+    undirected: bool,
+    cb: &mut [f64],
+) {
+    let node_count = graph.node_count().index();
+
+    let mut dist = vec![-1_i64; node_count];
+    let mut sigma = vec![0.0_f64; node_count];
+    let mut preds: Vec<Vec<NI>> = vec![Vec::new(); node_count];
+    let mut stack = Vec::with_capacity(node_count);
+    let mut queue = VecDeque::new();
+
+    dist[s.index()] = 0;
+    sigma[s.index()] = 1.0;
+    queue.push_back(s);
+
+    while let Some(v) = queue.pop_front() {
+        stack.push(v);
+
+        for w in neighbors(graph, v, undirected) {
+            if dist[w.index()] < 0 {
+                dist[w.index()] = dist[v.index()] + 1;
+                queue.push_back(w);
+            }
+
+            if dist[w.index()] == dist[v.index()] + 1 {
+                sigma[w.index()] += sigma[v.index()];
+                preds[w.index()].push(v);
+            }
+        }
+    }
+
+    let mut delta = vec![0.0_f64; node_count];
+    while let Some(w) = stack.pop() {
+        for &v in &preds[w.index()] {
+            delta[v.index()] += (sigma[v.index()] / sigma[w.index()]) * (1.0 + delta[w.index()]);
+        }
+
+        if w != s {
+            cb[w.index()] += delta[w.index()];
+        }
+    }
+}
+
+// Out-neighbors, plus in-neighbors when the graph should be scored as undirected.
+fn neighbors<NI: Idx>(
+    graph: &DirectedCsrGraph<NI>,
+    u: NI,
+    undirected: bool,
+) -> impl Iterator<Item = NI> + '_ {
+    let in_neighbors: &[NI] = if undirected {
+        graph.in_neighbors(u)
+    } else {
+        &[]
+    };
+    graph.out_neighbors(u).iter().chain(in_neighbors).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_graph() {
+        // 0 -> 1 -> 2: node 1 sits on the only shortest path between 0 and 2.
+        let graph: DirectedCsrGraph<usize> =
+            GraphBuilder::new().edges(vec![(0, 1), (1, 2)]).build();
+
+        let cb = betweenness_centrality(&graph, true);
+
+        assert_eq!(cb[1], 1.0);
+        assert_eq!(cb[0], 0.0);
+        assert_eq!(cb[2], 0.0);
+    }
+}
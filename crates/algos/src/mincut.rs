@@ -0,0 +1,211 @@
+use std::hash::Hash;
+
+use rand::seq::SliceRandom;
+use rayon::prelude::*;
+
+use crate::{dss::DisjointSetStruct, prelude::*};
+
+// Number of independent Karger trials run by `global_min_cut_default`,
+// which doesn't take a trial count.
+const DEFAULT_TRIALS: usize = 32;
+
+// At or below this many remaining supernodes, Karger-Stein stops recursing
+// and falls back to a single direct contraction down to 2 nodes.
+const BASE_CASE_NODES: usize = 6;
+
+pub struct MinCut<NI> {
+    pub cut_size: usize,
+    pub partition: Vec<NI>,
+}
+
+// Karger's randomized contraction: each trial contracts random edges via
+// DisjointSetStruct::union down to 2 components and counts the crossing
+// edges; the smallest cut across all (parallel) trials wins.
+pub fn global_min_cut<NI: Idx + Hash + Send + Sync>(
+    graph: &DirectedCsrGraph<NI>,
+    trials: usize,
+) -> MinCut<NI> {
+    let edges = collect_edges(graph);
+    let node_count = graph.node_count().index();
+
+    (0..trials.max(1))
+        .into_par_iter()
+        .map(|_| karger_contract(node_count, &edges, 2))
+        .min_by_key(|cut| cut.cut_size)
+        .expect("at least one trial is run")
+}
+
+pub fn global_min_cut_default<NI: Idx + Hash + Send + Sync>(
+    graph: &DirectedCsrGraph<NI>,
+) -> MinCut<NI> {
+    global_min_cut(graph, DEFAULT_TRIALS)
+}
+
+// Karger-Stein: contract down to roughly n / sqrt(2) supernodes, then branch
+// into two independent sub-contractions and keep the smaller cut.
+pub fn global_min_cut_karger_stein<NI: Idx + Hash + Send + Sync>(
+    graph: &DirectedCsrGraph<NI>,
+) -> MinCut<NI> {
+    let edges = collect_edges(graph);
+    let node_count = graph.node_count().index();
+    let dss = DisjointSetStruct::new(node_count);
+
+    karger_stein(&edges, dss, node_count)
+}
+
+fn karger_stein<NI: Idx + Hash + Send + Sync>(
+    edges: &[(NI, NI)],
+    dss: DisjointSetStruct<NI>,
+    remaining: usize,
+) -> MinCut<NI> {
+    if remaining <= BASE_CASE_NODES {
+        let dss = contract_to(dss, edges, remaining, 2);
+        return measure_cut(&dss, edges);
+    }
+
+    let target = ((remaining as f64 / std::f64::consts::SQRT_2).ceil() as usize).max(2);
+    let dss = contract_to(dss, edges, remaining, target);
+
+    let (left, right) = rayon::join(
+        || karger_stein(edges, dss.clone(), target),
+        || karger_stein(edges, dss.clone(), target),
+    );
+
+    if left.cut_size <= right.cut_size {
+        left
+    } else {
+        right
+    }
+}
+
+fn karger_contract<NI: Idx + Hash>(
+    node_count: usize,
+    edges: &[(NI, NI)],
+    target: usize,
+) -> MinCut<NI> {
+    let dss = DisjointSetStruct::new(node_count);
+    let dss = contract_to(dss, edges, node_count, target);
+    measure_cut(&dss, edges)
+}
+
+// Randomly contracts edges into `dss` until only `target` components remain
+// (or no contractible edge is left), returning the updated structure.
+fn contract_to<NI: Idx + Hash>(
+    dss: DisjointSetStruct<NI>,
+    edges: &[(NI, NI)],
+    mut remaining: usize,
+    target: usize,
+) -> DisjointSetStruct<NI> {
+    let mut rng = rand::thread_rng();
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.shuffle(&mut rng);
+
+    for idx in order {
+        if remaining <= target {
+            break;
+        }
+
+        let (u, v) = edges[idx];
+        if dss.find(u) != dss.find(v) {
+            dss.union(u, v);
+            remaining -= 1;
+        }
+    }
+
+    dss
+}
+
+fn measure_cut<NI: Idx + Hash>(dss: &DisjointSetStruct<NI>, edges: &[(NI, NI)]) -> MinCut<NI> {
+    let cut_size = edges
+        .iter()
+        .filter(|&&(u, v)| dss.find(u) != dss.find(v))
+        .count();
+
+    let representative = dss.find(edges.first().map_or(NI::new(0), |&(u, _)| u));
+    let partition = (0..dss.len())
+        .map(NI::new)
+        .filter(|&n| dss.find(n) == representative)
+        .collect();
+
+    MinCut {
+        cut_size,
+        partition,
+    }
+}
+
+// Reciprocal arcs (both (u, v) and (v, u) present, the natural way an
+// undirected edge ends up in a directed CSR) are intentionally left
+// un-deduped here and counted as two parallel edges, matching how
+// `articulation`'s low-link walk treats the same pair as redundant
+// connectivity rather than collapsing it to one logical edge.
+fn collect_edges<NI: Idx>(graph: &DirectedCsrGraph<NI>) -> Vec<(NI, NI)> {
+    (0..graph.node_count().index())
+        .flat_map(|u| {
+            let u = NI::new(u);
+            graph
+                .out_neighbors(u)
+                .iter()
+                .map(move |&v| (u, v))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_triangles_joined_by_one_edge() {
+        // Two triangles (0,1,2) and (3,4,5) joined by the single edge (2, 3):
+        // the global min cut is exactly that one edge.
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+            .build();
+
+        let cut = global_min_cut(&graph, 64);
+
+        assert_eq!(cut.cut_size, 1);
+    }
+
+    #[test]
+    fn default_trials_find_the_same_cut() {
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+            .build();
+
+        let cut = global_min_cut_default(&graph);
+
+        assert_eq!(cut.cut_size, 1);
+    }
+
+    #[test]
+    fn karger_stein_finds_the_min_cut() {
+        // Two 4-cycles (0..3, 4..7) joined by the single edge (3, 4). Large
+        // enough (> BASE_CASE_NODES) to exercise the actual recursive split
+        // into two sub-contractions, not just the single-shot base case.
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 0),
+                (4, 5),
+                (5, 6),
+                (6, 7),
+                (7, 4),
+                (3, 4),
+            ])
+            .build();
+
+        // A single Karger-Stein run is randomized and not guaranteed to hit
+        // the exact min cut, so repeat and take the best of several runs.
+        let best = (0..20)
+            .map(|_| global_min_cut_karger_stein(&graph))
+            .min_by_key(|cut| cut.cut_size)
+            .unwrap();
+
+        assert_eq!(best.cut_size, 1);
+        assert!(!best.partition.is_empty() && best.partition.len() < 8);
+    }
+}
@@ -0,0 +1,104 @@
+use std::hash::Hash;
+
+use crate::{dss::DisjointSetStruct, prelude::*};
+
+// Kosaraju's algorithm: DFS for finish order, then DFS the transpose
+// (in_neighbors) in reverse finish order, unioning each reached node into
+// the current component.
+pub fn scc<NI: Idx + Hash>(graph: &DirectedCsrGraph<NI>) -> DisjointSetStruct<NI> {
+    let node_count = graph.node_count().index();
+    let dss = DisjointSetStruct::new(node_count);
+
+    let finish_order = finish_order(graph);
+
+    let mut visited = vec![false; node_count];
+    let mut stack = Vec::new();
+
+    for &root in finish_order.iter().rev() {
+        if visited[root.index()] {
+            continue;
+        }
+
+        visited[root.index()] = true;
+        stack.push(root);
+
+        while let Some(u) = stack.pop() {
+            for &v in graph.in_neighbors(u) {
+                if !visited[v.index()] {
+                    visited[v.index()] = true;
+                    dss.union(root, v);
+                    stack.push(v);
+                }
+            }
+        }
+    }
+
+    dss
+}
+
+// Iterative DFS over `out_neighbors` recording the finish order of every
+// node. Each stack frame tracks the index of the next neighbor to visit, so
+// the traversal survives arbitrarily deep graphs without recursing.
+fn finish_order<NI: Idx>(graph: &DirectedCsrGraph<NI>) -> Vec<NI> {
+    let node_count = graph.node_count().index();
+    let mut visited = vec![false; node_count];
+    let mut order = Vec::with_capacity(node_count);
+    let mut stack: Vec<(NI, usize)> = Vec::new();
+
+    for start in 0..node_count {
+        let start = NI::new(start);
+        if visited[start.index()] {
+            continue;
+        }
+
+        visited[start.index()] = true;
+        stack.push((start, 0));
+
+        while let Some(&mut (u, ref mut next)) = stack.last_mut() {
+            let neighbors = graph.out_neighbors(u);
+
+            match neighbors.get(*next) {
+                Some(&v) => {
+                    *next += 1;
+                    if !visited[v.index()] {
+                        visited[v.index()] = true;
+                        stack.push((v, 0));
+                    }
+                }
+                None => {
+                    order.push(u);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_sccs() {
+        // 0 <-> 1 form a cycle; 2 is reachable from 1 but has no way back.
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 0), (1, 2)])
+            .build();
+
+        let dss = scc(&graph);
+
+        assert_eq!(dss.find(0), dss.find(1));
+        assert_ne!(dss.find(1), dss.find(2));
+    }
+
+    #[test]
+    fn single_node_components() {
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new().edges(vec![(0, 1)]).build();
+
+        let dss = scc(&graph);
+
+        assert_ne!(dss.find(0), dss.find(1));
+    }
+}
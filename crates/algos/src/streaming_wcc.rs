@@ -0,0 +1,95 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use crate::{dss::DisjointSetStruct, prelude::*};
+
+// Number of edges to be processed in batch by a single thread, matching
+// `wcc_rayon_chunks`'s chunking.
+const CHUNK_SIZE: usize = 16384;
+
+// Online variant of wcc: a live DisjointSetStruct plus a running count of
+// distinct roots, decremented on each successful union, so component_count
+// is O(1) instead of scanning the whole structure.
+pub struct StreamingWcc<NI> {
+    dss: DisjointSetStruct<NI>,
+    component_count: AtomicUsize,
+}
+
+impl<NI: Idx> StreamingWcc<NI> {
+    pub fn new(node_count: usize) -> Self {
+        Self {
+            dss: DisjointSetStruct::new(node_count),
+            component_count: AtomicUsize::new(node_count),
+        }
+    }
+
+    // Returns true if the edge merged two previously disjoint components.
+    pub fn insert_edge(&self, u: NI, v: NI) -> bool {
+        let merged = self.dss.union(u, v);
+        if merged {
+            self.component_count.fetch_sub(1, Ordering::AcqRel);
+        }
+        merged
+    }
+
+    // Same chunked-rayon pattern as wcc_rayon_chunks.
+    pub fn insert_edges(&self, edges: &[(NI, NI)])
+    where
+        NI: Sync,
+    {
+        edges.par_chunks(CHUNK_SIZE).for_each(|chunk| {
+            for &(u, v) in chunk {
+                self.insert_edge(u, v);
+            }
+        });
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.component_count.load(Ordering::Acquire)
+    }
+
+    pub fn component_of(&self, node: NI) -> NI {
+        self.dss.find(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merging_reduces_component_count() {
+        let wcc = StreamingWcc::<usize>::new(4);
+        assert_eq!(wcc.component_count(), 4);
+
+        assert!(wcc.insert_edge(0, 1));
+        assert_eq!(wcc.component_count(), 3);
+
+        assert!(!wcc.insert_edge(0, 1));
+        assert_eq!(wcc.component_count(), 3);
+
+        assert!(wcc.insert_edge(2, 3));
+        assert_eq!(wcc.component_count(), 2);
+
+        assert_eq!(wcc.component_of(0), wcc.component_of(1));
+        assert_ne!(wcc.component_of(0), wcc.component_of(2));
+    }
+
+    #[test]
+    fn batched_insert_merges_all_components() {
+        let wcc = StreamingWcc::<usize>::new(6);
+
+        wcc.insert_edges(&[(0, 1), (1, 2), (3, 4), (4, 5)]);
+
+        assert_eq!(wcc.component_count(), 2);
+        assert_eq!(wcc.component_of(0), wcc.component_of(2));
+        assert_eq!(wcc.component_of(3), wcc.component_of(5));
+        assert_ne!(wcc.component_of(0), wcc.component_of(3));
+
+        wcc.insert_edges(&[(2, 3)]);
+
+        assert_eq!(wcc.component_count(), 1);
+        assert_eq!(wcc.component_of(0), wcc.component_of(5));
+    }
+}